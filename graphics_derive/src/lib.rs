@@ -3,57 +3,280 @@
 extern crate proc_macro;
 
 use crate::proc_macro::TokenStream;
-use quote::quote;
-use syn;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, DeriveInput, Ident, LitInt};
 
-#[proc_macro_derive(Graphics)]
+/// Options parsed from the `#[graphics(..)]` helper attribute.
+///
+/// Everything is optional: when a value is missing we fall back to the
+/// `WIDTH`/`HEIGHT` consts of the enclosing driver module and to the crate's
+/// `Color` enum, so a plain `#[derive(Graphics)]` still produces a working
+/// buffer for the module it lives in.
+struct GraphicsArgs {
+    /// Name of the generated buffer struct (defaults to the annotated type).
+    name: Option<Ident>,
+    /// Pixel width; `None` means "use the module's `WIDTH`".
+    width: Option<LitInt>,
+    /// Pixel height; `None` means "use the module's `HEIGHT`".
+    height: Option<LitInt>,
+    /// Color type the buffer is drawn with.
+    color: Ident,
+    /// Double the buffer for black/red (tricolor) panels.
+    tricolor: bool,
+}
+
+impl Default for GraphicsArgs {
+    fn default() -> Self {
+        GraphicsArgs {
+            name: None,
+            width: None,
+            height: None,
+            color: format_ident!("Color"),
+            tricolor: false,
+        }
+    }
+}
+
+fn parse_args(ast: &DeriveInput) -> syn::Result<GraphicsArgs> {
+    let mut args = GraphicsArgs::default();
+
+    for attr in &ast.attrs {
+        if !attr.path().is_ident("graphics") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                args.name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("width") {
+                args.width = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("height") {
+                args.height = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("color") {
+                args.color = meta.value()?.parse()?;
+            } else if meta.path.is_ident("tricolor") {
+                args.tricolor = true;
+            } else {
+                return Err(meta.error("unsupported `graphics` attribute key"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(args)
+}
+
+#[proc_macro_derive(Graphics, attributes(graphics))]
 pub fn graphics_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
 
-    // Build the trait implementation
-    impl_graphics_macro(&ast)
+    // Build the trait implementation, surfacing attribute errors as a spanned
+    // `compile_error!` rather than panicking the compiler.
+    match impl_graphics_macro(&ast) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error().into(),
+    }
 }
 
+fn impl_graphics_macro(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let args = parse_args(ast)?;
+
+    let name = args.name.unwrap_or_else(|| ast.ident.clone());
+    let color = &args.color;
+
+    // Fall back to the enclosing module's consts when no explicit size is given.
+    let width = match &args.width {
+        Some(lit) => quote!(#lit),
+        None => quote!(WIDTH),
+    };
+    let height = match &args.height {
+        Some(lit) => quote!(#lit),
+        None => quote!(HEIGHT),
+    };
+
+    // Tricolor panels keep a second bitplane for the red/black channel, so the
+    // backing buffer is twice the size of a plain black/white panel.
+    let planes = if args.tricolor {
+        quote!(2)
+    } else {
+        quote!(1)
+    };
+    let buffer_len = quote!(#width as usize / 8 * #height as usize * #planes);
+
+    let doc = format!(
+        "Full-size display buffer for use with the generated `{}` panel.\n\n\
+         Can also be constructed manually:\n\
+         `buffer: [DEFAULT_BACKGROUND_COLOR.get_byte_value(); {{ {} }}]`",
+        name,
+        quote!(#buffer_len),
+    );
 
-fn impl_graphics_macro(ast: &syn::DeriveInput) -> TokenStream {
-    let name = &ast.ident;
     let gen = quote! {
-        use crate::epd2in9::{DEFAULT_BACKGROUND_COLOR, HEIGHT, WIDTH};
         use crate::graphics::{Display, DisplayRotation};
         use crate::prelude::*;
-        use embedded_graphics::prelude::*;
-
-        /// Display with Fullsize buffer for use with the 2in9 EPD
-        ///
-        /// Can also be manuall constructed:
-        /// `buffer: [DEFAULT_BACKGROUND_COLOR.get_byte_value(); WIDTH / 8 * HEIGHT]`
-        pub struct Display2in9 {
-            buffer: [u8; WIDTH as usize * HEIGHT as usize / 8],
+
+        #[doc = #doc]
+        pub struct #name {
+            buffer: [u8; #buffer_len],
             rotation: DisplayRotation,
         }
 
-        impl Default for Display2in9 {
+        impl Default for #name {
             fn default() -> Self {
-                Display2in9 {
-                    buffer: [DEFAULT_BACKGROUND_COLOR.get_byte_value();
-                        WIDTH as usize * HEIGHT as usize / 8],
+                #name {
+                    buffer: [DEFAULT_BACKGROUND_COLOR.get_byte_value(); #buffer_len],
                     rotation: DisplayRotation::default(),
                 }
             }
         }
 
-        impl Drawing<Color> for Display2in9 {
-            fn draw<T>(&mut self, item_pixels: T)
+        #[cfg(feature = "graphics")]
+        impl embedded_graphics_core::geometry::OriginDimensions for #name {
+            fn size(&self) -> embedded_graphics_core::geometry::Size {
+                // The logical canvas is transposed for the quarter-turn rotations.
+                match self.rotation() {
+                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                        embedded_graphics_core::geometry::Size::new(#width, #height)
+                    }
+                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                        embedded_graphics_core::geometry::Size::new(#height, #width)
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "graphics")]
+        impl embedded_graphics_core::draw_target::DrawTarget for #name {
+            type Color = #color;
+            type Error = core::convert::Infallible;
+
+            fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
             where
-                T: IntoIterator<Item = Pixel<Color>>,
+                I: IntoIterator<Item = embedded_graphics_core::Pixel<Self::Color>>,
             {
-                self.draw_helper(WIDTH, HEIGHT, item_pixels);
+                use embedded_graphics_core::pixelcolor::BinaryColor;
+
+                let rotation = self.rotation();
+                let buffer = self.get_mut_buffer();
+                for embedded_graphics_core::Pixel(point, color) in pixels {
+                    if point.x < 0 || point.y < 0 {
+                        continue;
+                    }
+                    let (x, y) = (point.x as u32, point.y as u32);
+                    // Clip in logical space (transposed for the quarter turns) so
+                    // the rotation subtractions below can never underflow.
+                    let (logical_w, logical_h) = match rotation {
+                        DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (#width, #height),
+                        DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (#height, #width),
+                    };
+                    if x >= logical_w || y >= logical_h {
+                        continue;
+                    }
+                    // Undo the logical rotation so we address the panel's native frame.
+                    let (nx, ny) = match rotation {
+                        DisplayRotation::Rotate0 => (x, y),
+                        DisplayRotation::Rotate90 => (#width - 1 - y, x),
+                        DisplayRotation::Rotate180 => (#width - 1 - x, #height - 1 - y),
+                        DisplayRotation::Rotate270 => (y, #height - 1 - x),
+                    };
+                    let index = (nx / 8 + ny * (#width / 8)) as usize;
+                    let bit = 0x80u8 >> (nx % 8);
+                    // Packed 1bpp: a set bit is white, a cleared bit is black.
+                    match BinaryColor::from(color) {
+                        BinaryColor::On => buffer[index] &= !bit,
+                        BinaryColor::Off => buffer[index] |= bit,
+                    }
+                }
+                Ok(())
+            }
+
+            fn fill_solid(
+                &mut self,
+                area: &embedded_graphics_core::primitives::Rectangle,
+                color: Self::Color,
+            ) -> Result<(), Self::Error> {
+                use embedded_graphics_core::geometry::Dimensions;
+                use embedded_graphics_core::pixelcolor::BinaryColor;
+
+                let area = area.intersection(&self.bounding_box());
+                let bottom_right = match area.bottom_right() {
+                    Some(bottom_right) => bottom_right,
+                    None => return Ok(()),
+                };
+
+                // The whole-byte fast path only holds in the panel's native frame;
+                // a rotated buffer is handled pixel-by-pixel.
+                if self.rotation() != DisplayRotation::Rotate0 {
+                    return self.fill_contiguous(&area, core::iter::repeat(color));
+                }
+
+                let x0 = area.top_left.x as u32;
+                let x1 = x0 + area.size.width;
+                let y0 = area.top_left.y as u32;
+                let y1 = bottom_right.y as u32;
+
+                // Split the span into ragged left/right edges and a byte-aligned
+                // middle that can be written a whole byte at a time.
+                let mut mid_start = (x0 + 7) & !7;
+                let mut mid_end = x1 & !7;
+                if mid_start > mid_end {
+                    mid_start = x1;
+                    mid_end = x1;
+                }
+
+                let on = matches!(BinaryColor::from(color), BinaryColor::On);
+                let byte = if on { 0x00u8 } else { 0xffu8 };
+                let row_bytes = (#width / 8) as usize;
+                let buffer = self.get_mut_buffer();
+
+                for row in y0..=y1 {
+                    let base = row as usize * row_bytes;
+                    for px in x0..mid_start {
+                        let index = base + (px / 8) as usize;
+                        let bit = 0x80u8 >> (px % 8);
+                        if on {
+                            buffer[index] &= !bit;
+                        } else {
+                            buffer[index] |= bit;
+                        }
+                    }
+                    if mid_start < mid_end {
+                        let start = base + (mid_start / 8) as usize;
+                        let end = base + (mid_end / 8) as usize;
+                        buffer[start..end].fill(byte);
+                    }
+                    for px in mid_end..x1 {
+                        let index = base + (px / 8) as usize;
+                        let bit = 0x80u8 >> (px % 8);
+                        if on {
+                            buffer[index] &= !bit;
+                        } else {
+                            buffer[index] |= bit;
+                        }
+                    }
+                }
+                Ok(())
             }
         }
 
-        impl Display for Display2in9 {
+        #[cfg(feature = "graphics")]
+        impl #name {
+            /// Fill a rectangle with a solid color.
+            ///
+            /// Delegates to the `DrawTarget::fill_solid` override, which writes whole
+            /// bytes for the byte-aligned middle of each row when the buffer is not
+            /// rotated, and sets the ragged edges (or every pixel, when rotated) one
+            /// by one.
+            pub fn fill_rect(&mut self, area: embedded_graphics_core::primitives::Rectangle, color: #color) {
+                use embedded_graphics_core::draw_target::DrawTarget;
+                // fill_solid is infallible for this buffer.
+                let _ = self.fill_solid(&area, color);
+            }
+        }
+
+        impl Display for #name {
             fn buffer(&self) -> &[u8] {
                 &self.buffer
             }
@@ -70,13 +293,8 @@ fn impl_graphics_macro(ast: &syn::DeriveInput) -> TokenStream {
                 self.rotation
             }
         }
-        impl HelloMacro for #name {
-            fn hello_macro() {
-                println!("Hello, Macro! My name is {}", stringify!(#name));
-            }
-        }
     };
-    gen.into()
+    Ok(gen.into())
 }
 
 #[cfg(test)]
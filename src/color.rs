@@ -0,0 +1,55 @@
+//! The black/white color used by the 1bpp Waveshare panels.
+
+/// Color of a single pixel.
+///
+/// The panels are 1bpp, so a pixel is either black or white.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Color {
+    /// A black pixel (a cleared bit in the packed buffer).
+    Black,
+    /// A white pixel (a set bit in the packed buffer).
+    White,
+}
+
+impl Color {
+    /// Returns the byte that, when repeated, fills a buffer with this color.
+    ///
+    /// White is `0xff` (all bits set) and black is `0x00`.
+    pub fn get_byte_value(self) -> u8 {
+        match self {
+            Color::White => 0xff,
+            Color::Black => 0x00,
+        }
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::pixelcolor::{
+    raw::RawU1, BinaryColor, PixelColor,
+};
+
+#[cfg(feature = "graphics")]
+impl PixelColor for Color {
+    type Raw = RawU1;
+}
+
+#[cfg(feature = "graphics")]
+impl From<BinaryColor> for Color {
+    fn from(color: BinaryColor) -> Self {
+        // embedded-graphics paints "on" pixels, which map to black ink.
+        match color {
+            BinaryColor::On => Color::Black,
+            BinaryColor::Off => Color::White,
+        }
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl From<Color> for BinaryColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Black => BinaryColor::On,
+            Color::White => BinaryColor::Off,
+        }
+    }
+}
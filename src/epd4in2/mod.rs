@@ -83,6 +83,13 @@ pub struct EPD4in2<SPI, CS, BUSY, DC, RST> {
     color: Color,
     /// Refresh LUT
     refresh: RefreshLUT,
+    /// Shadow copy of the last transmitted frame.
+    ///
+    /// A correct partial refresh needs both the previous ("old") image and the
+    /// new one inside the partial window so the controller can compute the
+    /// W→B / B→W transitions. We keep the last full frame here and replay the
+    /// relevant window as DTM1 on the next partial update.
+    old_frame: [u8; WIDTH as usize / 8 * HEIGHT as usize],
 }
 
 impl<SPI, CS, BUSY, DC, RST, SpiE, PinRE, PinWE> InternalWiAdditions<SPI, CS, BUSY, DC, RST, SpiE, PinRE, PinWE>
@@ -176,7 +183,8 @@ where
         let mut epd = EPD4in2 {
             di,
             color,
-            refresh: RefreshLUT::FULL,
+            refresh: RefreshLUT::NORMAL,
+            old_frame: [color.get_byte_value(); WIDTH as usize / 8 * HEIGHT as usize],
         };
 
         epd.init(spi, delay)?;
@@ -232,6 +240,11 @@ where
         self.di
             .cmd_with_data(spi, Command::DATA_START_TRANSMISSION_2, buffer)?;
 
+        // Remember this frame so the next partial update can send it as DTM1.
+        if buffer.len() == self.old_frame.len() {
+            self.old_frame.copy_from_slice(buffer);
+        }
+
         self.wait_until_idle();
         Ok(())
     }
@@ -244,12 +257,19 @@ where
         y: u32,
         width: u32,
         height: u32,
-    ) -> Result<(), SPI::Error> {
-        if buffer.len() as u32 != width / 8 * height {
-            //TODO: panic!! or sth like that
-            //return Err("Wrong buffersize");
+    ) -> Result<(), Error<SpiE, PinRE, PinWE>> {
+        if buffer.len() as u32 != width / 8 * height
+            || x + width > WIDTH
+            || y + height > HEIGHT
+        {
+            return Err(Error::IncorrectBufferSize);
         }
 
+        // A clean fast partial update needs the fastest waveform. Remember the
+        // caller's LUT so a later full refresh isn't silently left on FAST.
+        let previous_refresh = self.refresh;
+        self.set_lut(spi, Some(RefreshLUT::FAST))?;
+
         self.di.cmd(spi, Command::PARTIAL_IN)?;
         self.di.cmd(spi, Command::PARTIAL_WINDOW)?;
         self.di.data(spi, &[(x >> 8) as u8])?;
@@ -267,19 +287,37 @@ where
 
         self.di.data(spi, &[0x01])?; // Gates scan both inside and outside of the partial window. (default)
 
-        //TODO: handle dtm somehow
-        let is_dtm1 = false;
-        if is_dtm1 {
-            self.di.cmd(spi, Command::DATA_START_TRANSMISSION_1)? //TODO: check if data_start transmission 1 also needs "old"/background data here
-        } else {
-            self.di.cmd(spi, Command::DATA_START_TRANSMISSION_2)?
+        let row_bytes = (WIDTH / 8) as usize;
+        let x_byte = (x & 0xf8) as usize / 8;
+        let width_bytes = (width / 8) as usize;
+
+        // DTM1: the previous ("old") image for this window, pulled from the
+        // shadow frame so the controller can compute the transitions.
+        self.di.cmd(spi, Command::DATA_START_TRANSMISSION_1)?;
+        for row in 0..height as usize {
+            let src = (y as usize + row) * row_bytes + x_byte;
+            self.di.data(spi, &self.old_frame[src..src + width_bytes])?;
         }
 
-        self.di.data(spi, buffer)?;
+        // DTM2: the new image.
+        self.di
+            .cmd_with_data(spi, Command::DATA_START_TRANSMISSION_2, buffer)?;
 
         self.di.cmd(spi, Command::PARTIAL_OUT)?;
 
+        // Fold the new window into the shadow so a subsequent partial update
+        // diffs against what is actually on the panel.
+        for row in 0..height as usize {
+            let dst = (y as usize + row) * row_bytes + x_byte;
+            self.old_frame[dst..dst + width_bytes]
+                .copy_from_slice(&buffer[row * width_bytes..row * width_bytes + width_bytes]);
+        }
+
         self.wait_until_idle();
+
+        // Restore the previously selected waveform so a following full refresh
+        // isn't degraded to the ghost-prone FAST LUT.
+        self.set_lut(spi, Some(previous_refresh))?;
         Ok(())
     }
 
@@ -305,6 +343,12 @@ where
         self.di
             .data_x_times(spi, color_value, WIDTH / 8 * HEIGHT)?;
 
+        // Keep the shadow frame in sync so the next partial update has a
+        // correct "old" image to diff against.
+        for byte in self.old_frame.iter_mut() {
+            *byte = color_value;
+        }
+
         self.wait_until_idle();
         Ok(())
     }
@@ -334,10 +378,20 @@ where
             self.refresh = refresh_lut;
         }
         match self.refresh {
-            RefreshLUT::FULL => {
+            // Leave the waveforms at the controller's built-in OTP defaults.
+            RefreshLUT::INTERNAL => Ok(()),
+            RefreshLUT::NORMAL => {
                 self.set_lut_helper(spi, &LUT_VCOM0, &LUT_WW, &LUT_BW, &LUT_WB, &LUT_BB)
             }
-            RefreshLUT::QUICK => self.set_lut_helper(
+            RefreshLUT::MEDIUM => self.set_lut_helper(
+                spi,
+                &LUT_VCOM0_MEDIUM,
+                &LUT_WW_MEDIUM,
+                &LUT_BW_MEDIUM,
+                &LUT_WB_MEDIUM,
+                &LUT_BB_MEDIUM,
+            ),
+            RefreshLUT::FAST => self.set_lut_helper(
                 spi,
                 &LUT_VCOM0_QUICK,
                 &LUT_WW_QUICK,
@@ -378,6 +432,68 @@ where
         self.di.data(spi, &[h as u8])
     }
 
+    /// Inverts how the panel interprets the frame data without re-rendering
+    /// the host-side buffer.
+    ///
+    /// Flips the DDX bits of [`VCOM_AND_DATA_INTERVAL_SETTING`], so black and
+    /// white (and the border) swap on the next refresh.
+    ///
+    /// [`VCOM_AND_DATA_INTERVAL_SETTING`]: Command::VCOM_AND_DATA_INTERVAL_SETTING
+    pub fn invert_color(&mut self, spi: &mut SPI, invert: bool) -> Result<(), SPI::Error> {
+        let value = if invert { 0xA7 } else { 0x97 };
+        self.di
+            .cmd_with_data(spi, Command::VCOM_AND_DATA_INTERVAL_SETTING, &[value])
+    }
+
+    /// Clears the whole panel to `color`, also adopting it as the background
+    /// color for subsequent operations.
+    pub fn clear_frame_with(&mut self, spi: &mut SPI, color: Color) -> Result<(), SPI::Error> {
+        self.set_background_color(color);
+        self.all_pixels(spi, color)
+    }
+
+    /// Fast full-screen test-pattern fill.
+    ///
+    /// Drives both data channels with the repeated `color` byte via
+    /// [`data_x_times`](DisplayInterface::data_x_times), so a solid diagnostic
+    /// pattern can be pushed without allocating a host-side buffer.
+    pub fn all_pixels(&mut self, spi: &mut SPI, color: Color) -> Result<(), SPI::Error> {
+        self.send_resolution(spi)?;
+
+        let color_value = color.get_byte_value();
+
+        self.di.cmd(spi, Command::DATA_START_TRANSMISSION_1)?;
+        self.di.data_x_times(spi, color_value, WIDTH / 8 * HEIGHT)?;
+
+        self.di.cmd(spi, Command::DATA_START_TRANSMISSION_2)?;
+        self.di.data_x_times(spi, color_value, WIDTH / 8 * HEIGHT)?;
+
+        for byte in self.old_frame.iter_mut() {
+            *byte = color_value;
+        }
+
+        self.wait_until_idle();
+        Ok(())
+    }
+
+    /// Uploads a caller-provided set of waveform tables at runtime.
+    ///
+    /// Advanced users can compute the five LUTs (VCOM, white-to-white,
+    /// black-to-white, white-to-black and black-to-black) for their ambient
+    /// temperature and push them directly instead of relying on the
+    /// [`RefreshLUT`] presets.
+    pub fn set_lut_custom(
+        &mut self,
+        spi: &mut SPI,
+        vcom: &[u8],
+        ww: &[u8],
+        bw: &[u8],
+        wb: &[u8],
+        bb: &[u8],
+    ) -> Result<(), SPI::Error> {
+        self.set_lut_helper(spi, vcom, ww, bw, wb, bb)
+    }
+
     fn set_lut_helper(
         &mut self,
         spi: &mut SPI,
@@ -0,0 +1,49 @@
+//! A simple Driver for Waveshare E-Ink Displays via SPI
+//!
+//! Built using [`embedded-hal`] traits.
+//!
+//! [`embedded-hal`]: https://docs.rs/embedded-hal
+
+#![no_std]
+
+pub mod color;
+pub(crate) mod interface;
+pub mod traits;
+
+#[cfg(feature = "graphics")]
+pub mod graphics;
+
+pub mod epd4in2;
+
+/// Errors returned by the driver.
+///
+/// Wraps the SPI bus and GPIO errors of the underlying [`embedded-hal`]
+/// peripherals, plus the driver's own misuse errors.
+///
+/// [`embedded-hal`]: https://docs.rs/embedded-hal
+pub enum Error<SpiE, PinRE, PinWE> {
+    /// Error while talking to the SPI bus.
+    Spi(SpiE),
+    /// Error while reading an input pin (e.g. BUSY).
+    PinRead(PinRE),
+    /// Error while writing an output pin (e.g. CS/DC/RST).
+    PinWrite(PinWE),
+    /// A caller-supplied buffer did not match the expected `width / 8 * height`,
+    /// or a partial-update window does not fit on the panel.
+    IncorrectBufferSize,
+}
+
+impl<SpiE, PinRE, PinWE> From<SpiE> for Error<SpiE, PinRE, PinWE> {
+    fn from(error: SpiE) -> Self {
+        Error::Spi(error)
+    }
+}
+
+/// Commonly used types, re-exported for convenience.
+pub mod prelude {
+    pub use crate::color::Color;
+    pub use crate::traits::{RefreshLUT, WaveshareDisplay};
+
+    #[cfg(feature = "graphics")]
+    pub use crate::graphics::{Display, DisplayRotation};
+}
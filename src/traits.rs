@@ -0,0 +1,133 @@
+//! Shared traits implemented by every Waveshare display driver.
+
+use embedded_hal::{
+    blocking::{delay::DelayMs, spi::Write},
+    digital::v2::*,
+};
+
+use crate::color::Color;
+use crate::Error;
+
+/// A controller command, convertible to its register address.
+pub(crate) trait Command {
+    fn address(self) -> u8;
+}
+
+/// Waveform preset, graded from the cleanest (slowest) to the fastest refresh.
+///
+/// Modelled on the uc8151 driver's speed tiers: [`INTERNAL`](RefreshLUT::INTERNAL)
+/// keeps the controller's built-in OTP waveforms untouched, while
+/// [`NORMAL`](RefreshLUT::NORMAL), [`MEDIUM`](RefreshLUT::MEDIUM) and
+/// [`FAST`](RefreshLUT::FAST) trade progressively more ghosting for speed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RefreshLUT {
+    /// Leave the controller's built-in (OTP) waveforms in place.
+    INTERNAL,
+    /// Slowest, cleanest full refresh.
+    NORMAL,
+    /// Intermediate speed-versus-ghosting tradeoff.
+    MEDIUM,
+    /// Fastest refresh, most prone to ghosting. Used for partial updates.
+    FAST,
+}
+
+impl Default for RefreshLUT {
+    fn default() -> Self {
+        RefreshLUT::NORMAL
+    }
+}
+
+/// Internal wiring additions not meant to be called by users directly.
+pub(crate) trait InternalWiAdditions<SPI, CS, BUSY, DC, RST, SpiE, PinRE, PinWE>
+where
+    SPI: Write<u8, Error = SpiE>,
+    CS: OutputPin<Error = PinWE>,
+    BUSY: InputPin<Error = PinRE>,
+    DC: OutputPin<Error = PinWE>,
+    RST: OutputPin<Error = PinWE>,
+{
+    type Error;
+
+    /// Initialises the display so it is ready to receive frames.
+    fn init<DELAY: DelayMs<u8>>(
+        &mut self,
+        spi: &mut SPI,
+        delay: &mut DELAY,
+    ) -> Result<(), Self::Error>;
+}
+
+/// All the functions to interact with a Waveshare E-Ink Display.
+pub trait WaveshareDisplay<SPI, CS, BUSY, DC, RST, SpiE, PinRE, PinWE>
+where
+    SPI: Write<u8, Error = SpiE>,
+    CS: OutputPin<Error = PinWE>,
+    BUSY: InputPin<Error = PinRE>,
+    DC: OutputPin<Error = PinWE>,
+    RST: OutputPin<Error = PinWE>,
+{
+    type Error;
+
+    /// Creates and initialises a new driver.
+    fn new<DELAY: DelayMs<u8>>(
+        spi: &mut SPI,
+        cs: CS,
+        busy: BUSY,
+        dc: DC,
+        rst: RST,
+        delay: &mut DELAY,
+    ) -> Result<Self, SPI::Error>
+    where
+        Self: Sized;
+
+    /// Wakes the device from sleep.
+    fn wake_up<DELAY: DelayMs<u8>>(
+        &mut self,
+        spi: &mut SPI,
+        delay: &mut DELAY,
+    ) -> Result<(), SPI::Error>;
+
+    /// Puts the device into deep sleep.
+    fn sleep(&mut self, spi: &mut SPI) -> Result<(), SPI::Error>;
+
+    /// Transmits a full frame to the display.
+    fn update_frame(&mut self, spi: &mut SPI, buffer: &[u8]) -> Result<(), SPI::Error>;
+
+    /// Transmits a partial frame into the given window.
+    fn update_partial_frame(
+        &mut self,
+        spi: &mut SPI,
+        buffer: &[u8],
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Error<SpiE, PinRE, PinWE>>;
+
+    /// Displays the transmitted frame.
+    fn display_frame(&mut self, spi: &mut SPI) -> Result<(), SPI::Error>;
+
+    /// Clears the frame on the display to the background color.
+    fn clear_frame(&mut self, spi: &mut SPI) -> Result<(), SPI::Error>;
+
+    /// Sets the background color used for clears.
+    fn set_background_color(&mut self, color: Color);
+
+    /// Returns the currently configured background color.
+    fn background_color(&self) -> &Color;
+
+    /// Display width in pixels.
+    fn width(&self) -> u32;
+
+    /// Display height in pixels.
+    fn height(&self) -> u32;
+
+    /// Selects (and optionally switches to) a refresh waveform.
+    fn set_lut(
+        &mut self,
+        spi: &mut SPI,
+        refresh_rate: Option<RefreshLUT>,
+    ) -> Result<(), SPI::Error>;
+
+    /// Returns whether the device is still busy.
+    fn is_busy(&self) -> bool;
+}